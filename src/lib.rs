@@ -1,4 +1,5 @@
 use pyo3::prelude::*;
+use rayon::prelude::*;
 use uuid::Uuid;
 use std::sync::OnceLock;
 
@@ -12,10 +13,57 @@ const NAMESPACE_X500_UUID: Uuid = uuid::uuid!("6ba7b814-9dad-11d1-80b4-00c04fd43
 static NODE_ID: OnceLock<[u8; 6]> = OnceLock::new();
 
 /// Generate a version 1 UUID (time-based)
+///
+/// `node` pins the 48-bit node ID (its low 48 bits are used) and `clock_seq`
+/// pins the clock sequence; either defaults to the hashed pseudo-node and the
+/// library's own counter when omitted.
 #[pyfunction]
-fn uuid1() -> String {
-    // Initialize node ID once (simulate MAC address)
-    let node_id = NODE_ID.get_or_init(|| {
+#[pyo3(signature = (node=None, clock_seq=None))]
+fn uuid1(node: Option<u64>, clock_seq: Option<u16>) -> String {
+    let node_id = match node {
+        Some(n) => {
+            let b = n.to_be_bytes();
+            [b[2], b[3], b[4], b[5], b[6], b[7]]
+        }
+        None => *default_node_id(),
+    };
+    let uuid = match clock_seq {
+        Some(seq) => {
+            let context = uuid::Context::new(seq);
+            let ts = uuid::Timestamp::now(context);
+            Uuid::new_v1(ts, &node_id)
+        }
+        None => Uuid::now_v1(&node_id),
+    };
+    format_uuid_fast(&uuid)
+}
+
+/// Generate a version 6 UUID (reordered time-based, sortable)
+#[pyfunction]
+fn uuid6() -> String {
+    let uuid = Uuid::now_v6(default_node_id());
+    format_uuid_fast(&uuid)
+}
+
+/// Generate a version 7 UUID (Unix-time based, sortable)
+#[pyfunction]
+fn uuid7() -> String {
+    let uuid = Uuid::now_v7();
+    format_uuid_fast(&uuid)
+}
+
+/// Generate a version 8 UUID (custom) from 16 caller-supplied bytes
+#[pyfunction]
+fn uuid8(bytes: Vec<u8>) -> PyResult<String> {
+    let buf: [u8; 16] = bytes.as_slice().try_into()
+        .map_err(|_| pyo3::exceptions::PyValueError::new_err("bytes must be exactly 16 bytes long"))?;
+    let uuid = Uuid::new_v8(buf);
+    Ok(format_uuid_fast(&uuid))
+}
+
+/// Pseudo-node ID for the time-based versions - hashed from the process id once
+fn default_node_id() -> &'static [u8; 6] {
+    NODE_ID.get_or_init(|| {
         use std::hash::{Hash, Hasher};
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
         std::process::id().hash(&mut hasher);
@@ -28,15 +76,12 @@ fn uuid1() -> String {
             ((hash >> 32) & 0xff) as u8,
             ((hash >> 40) & 0xff) as u8 | 0x01, // Set multicast bit
         ]
-    });
-    
-    let uuid = Uuid::now_v1(node_id);
-    format_uuid_fast(&uuid)
+    })
 }
 
 /// Generate a version 3 UUID (MD5 hash-based)
 #[pyfunction]
-fn uuid3(namespace: &str, name: &str) -> PyResult<String> {
+fn uuid3(namespace: &PyAny, name: NameArg) -> PyResult<String> {
     let namespace_uuid = parse_namespace(namespace)?;
     let uuid = Uuid::new_v3(&namespace_uuid, name.as_bytes());
     Ok(format_uuid_fast(&uuid))
@@ -51,19 +96,38 @@ fn uuid4() -> String {
 
 /// Generate a version 5 UUID (SHA1 hash-based)
 #[pyfunction]
-fn uuid5(namespace: &str, name: &str) -> PyResult<String> {
+fn uuid5(namespace: &PyAny, name: NameArg) -> PyResult<String> {
     let namespace_uuid = parse_namespace(namespace)?;
     let uuid = Uuid::new_v5(&namespace_uuid, name.as_bytes());
     Ok(format_uuid_fast(&uuid))
 }
 
 /// Generate multiple version 4 UUIDs at once (batch operation)
+///
+/// The range is split across a Rayon thread pool with the GIL released for the
+/// duration of the work.
+#[pyfunction]
+fn uuid4_batch(py: Python, count: usize) -> Vec<String> {
+    py.allow_threads(|| {
+        (0..count)
+            .into_par_iter()
+            .map(|_| format_uuid_fast(&Uuid::new_v4()))
+            .collect()
+    })
+}
+
+/// Generate `count` version 4 UUIDs as a single flat `count * 16` byte buffer
+///
+/// Skips hyphenated string formatting entirely, for consumers inserting into a
+/// binary column.
 #[pyfunction]
-fn uuid4_batch(count: usize) -> Vec<String> {
-    (0..count).map(|_| {
-        let uuid = Uuid::new_v4();
-        format_uuid_fast(&uuid)
-    }).collect()
+fn uuid4_batch_bytes(py: Python, count: usize) -> Vec<u8> {
+    py.allow_threads(|| {
+        (0..count)
+            .into_par_iter()
+            .flat_map_iter(|_| Uuid::new_v4().into_bytes())
+            .collect()
+    })
 }
 
 /// Fast UUID formatting without allocation overhead
@@ -72,8 +136,32 @@ fn format_uuid_fast(uuid: &Uuid) -> String {
     uuid.as_hyphenated().to_string()
 }
 
-/// Helper function to parse namespace string or use predefined namespaces
-fn parse_namespace(namespace: &str) -> PyResult<Uuid> {
+/// A hash-based UUID name, accepted either as `str` or `bytes`
+#[derive(FromPyObject)]
+enum NameArg {
+    #[pyo3(transparent, annotation = "str")]
+    Str(String),
+    #[pyo3(transparent, annotation = "bytes")]
+    Bytes(Vec<u8>),
+}
+
+impl NameArg {
+    /// The raw bytes to hash: the UTF-8 encoding for a `str`, or the bytes as-is
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            NameArg::Str(s) => s.as_bytes(),
+            NameArg::Bytes(b) => b.as_slice(),
+        }
+    }
+}
+
+/// Helper function to parse a namespace, accepting a predefined name string,
+/// a UUID string, or a `FastUUID` instance.
+fn parse_namespace(namespace: &PyAny) -> PyResult<Uuid> {
+    if let Ok(fast) = namespace.extract::<PyRef<FastUUID>>() {
+        return Ok(fast.uuid);
+    }
+    let namespace: &str = namespace.extract()?;
     match namespace {
         "NAMESPACE_DNS" => Ok(NAMESPACE_DNS_UUID),
         "NAMESPACE_URL" => Ok(NAMESPACE_URL_UUID),
@@ -96,37 +184,116 @@ struct FastUUID {
 #[pymethods]
 impl FastUUID {
     #[new]
-    fn new(uuid_str: Option<&str>) -> PyResult<Self> {
-        let uuid = match uuid_str {
-            Some(s) => Uuid::parse_str(s)
+    #[pyo3(signature = (uuid_str=None, *, int=None, bytes=None))]
+    fn new(uuid_str: Option<&str>, int: Option<u128>, bytes: Option<Vec<u8>>) -> PyResult<Self> {
+        let uuid = match (uuid_str, int, bytes) {
+            (Some(s), _, _) => Uuid::parse_str(s)
                 .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid UUID: {}", e)))?,
-            None => Uuid::new_v4(),
+            (None, Some(n), _) => Uuid::from_u128(n),
+            (None, None, Some(b)) => {
+                let buf: [u8; 16] = b.as_slice().try_into()
+                    .map_err(|_| pyo3::exceptions::PyValueError::new_err("bytes must be exactly 16 bytes long"))?;
+                Uuid::from_bytes(buf)
+            }
+            (None, None, None) => Uuid::new_v4(),
         };
         Ok(FastUUID { uuid })
     }
-    
+
     fn __str__(&self) -> String {
         format_uuid_fast(&self.uuid)
     }
-    
+
     fn __repr__(&self) -> String {
         format!("FastUUID('{}')", self.__str__())
     }
-    
+
+    fn __hash__(&self) -> isize {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.uuid.hash(&mut hasher);
+        hasher.finish() as isize
+    }
+
+    fn __richcmp__(&self, other: PyRef<FastUUID>, op: pyo3::pyclass::CompareOp) -> bool {
+        // Order by the underlying 128-bit integer so comparison matches byte order
+        op.matches(self.uuid.as_u128().cmp(&other.uuid.as_u128()))
+    }
+
     #[getter]
     fn hex(&self) -> String {
         self.uuid.as_simple().to_string()
     }
-    
+
     #[getter]
     fn bytes(&self) -> Vec<u8> {
         self.uuid.as_bytes().to_vec()
     }
-    
+
+    #[getter]
+    fn bytes_le(&self) -> Vec<u8> {
+        self.uuid.to_bytes_le().to_vec()
+    }
+
+    #[getter]
+    fn int(&self) -> u128 {
+        self.uuid.as_u128()
+    }
+
+    #[getter]
+    fn urn(&self) -> String {
+        self.uuid.urn().to_string()
+    }
+
+    #[getter]
+    fn variant(&self) -> &'static str {
+        match self.uuid.get_variant() {
+            uuid::Variant::NCS => "reserved for NCS compatibility",
+            uuid::Variant::RFC4122 => "specified in RFC 4122",
+            uuid::Variant::Microsoft => "reserved for Microsoft compatibility",
+            _ => "reserved for future definition",
+        }
+    }
+
+    #[getter]
+    fn fields(&self) -> (u32, u16, u16, u8, u8, u64) {
+        let (time_low, time_mid, time_hi_version, rest) = self.uuid.as_fields();
+        let clock_seq_hi_variant = rest[0];
+        let clock_seq_low = rest[1];
+        let mut node: u64 = 0;
+        for &b in &rest[2..8] {
+            node = (node << 8) | b as u64;
+        }
+        (time_low, time_mid, time_hi_version, clock_seq_hi_variant, clock_seq_low, node)
+    }
+
     #[getter]
     fn version(&self) -> usize {
         self.uuid.get_version_num()
     }
+
+    /// Build a UUID from Windows GUID fields (the first three are little-endian)
+    #[staticmethod]
+    fn from_fields(data1: u32, data2: u16, data3: u16, data4: Vec<u8>) -> PyResult<Self> {
+        let d4: [u8; 8] = data4.as_slice().try_into()
+            .map_err(|_| pyo3::exceptions::PyValueError::new_err("data4 must be exactly 8 bytes long"))?;
+        Ok(FastUUID { uuid: Uuid::from_fields_le(data1, data2, data3, &d4) })
+    }
+
+    /// Build a UUID from 16 GUID bytes whose first three fields (4, 2 and 2 bytes)
+    /// are each stored little-endian
+    #[staticmethod]
+    fn from_guid_bytes(bytes: Vec<u8>) -> PyResult<Self> {
+        let buf: [u8; 16] = bytes.as_slice().try_into()
+            .map_err(|_| pyo3::exceptions::PyValueError::new_err("bytes must be exactly 16 bytes long"))?;
+        Ok(FastUUID { uuid: Uuid::from_bytes_le(buf) })
+    }
+
+    /// Serialize to 16 GUID bytes with each of the first three fields (4, 2 and 2
+    /// bytes) in little-endian order
+    fn to_guid_bytes(&self) -> Vec<u8> {
+        self.uuid.to_bytes_le().to_vec()
+    }
 }
 
 /// Python module definition
@@ -137,7 +304,11 @@ fn rust_uuid(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(uuid3, m)?)?;
     m.add_function(wrap_pyfunction!(uuid4, m)?)?;
     m.add_function(wrap_pyfunction!(uuid5, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid6, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid7, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid8, m)?)?;
     m.add_function(wrap_pyfunction!(uuid4_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid4_batch_bytes, m)?)?;
     
     // Add FastUUID class
     m.add_class::<FastUUID>()?;